@@ -2,8 +2,193 @@ use proc_macro::TokenStream;
 use proc_macro2::Span;
 use proc_macro_error::{abort, abort_call_site, proc_macro_error};
 use quote::quote;
-use std::path::PathBuf;
-use syn::{parse::Parse, parse_macro_input, punctuated::Punctuated, LitStr, Token};
+use std::path::{Path, PathBuf};
+use syn::{parse::Parse, parse_macro_input, punctuated::Punctuated, LitBool, LitStr, Token};
+
+/// The file name to search `PATH` for, given the requested executable
+/// `name`: on Windows, `.exe` is appended unless the name already ends with
+/// it.
+fn executable_file_name(name: &str) -> String {
+    if cfg!(windows) && !name.to_ascii_lowercase().ends_with(".exe") {
+        format!("{name}.exe")
+    } else {
+        name.to_string()
+    }
+}
+
+/// Returns true if `path` points at a file we're able to execute.
+#[cfg(unix)]
+fn is_executable_file(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    std::fs::metadata(path)
+        .map(|metadata| metadata.is_file() && metadata.permissions().mode() & 0o111 != 0)
+        .unwrap_or(false)
+}
+
+/// Returns true if `path` points at a file we're able to execute.
+#[cfg(not(unix))]
+fn is_executable_file(path: &Path) -> bool {
+    path.is_file()
+}
+
+/// Searches each directory in the `PATH` environment variable for an
+/// executable named `name` (or `name.exe` on Windows), returning the first
+/// match.
+fn find_executable_on_path(name: &str) -> Option<PathBuf> {
+    let path_var = std::env::var_os("PATH")?;
+    let file_name = executable_file_name(name);
+    std::env::split_paths(&path_var)
+        .map(|dir| dir.join(&file_name))
+        .find(|candidate| is_executable_file(candidate))
+}
+
+/// Per-project defaults for `include_tailwind!`, read from a
+/// `tailwindcss.toml` file or a `[package.metadata.tailwindcss]` table in
+/// `Cargo.toml`, both under `CARGO_MANIFEST_DIR`. Every field is optional;
+/// a field left unset here falls through to the macro's built-in default
+/// (the `TAILWINDCSS_*` env var for `config`, `input` and `tailwindcss_bin`;
+/// `true` for `minify`; no extra flags for `args`).
+#[derive(Debug, Default, serde::Deserialize)]
+struct ProjectConfig {
+    config: Option<String>,
+    input: Option<String>,
+    tailwindcss_bin: Option<String>,
+    minify: Option<bool>,
+    args: Option<Vec<String>>,
+}
+
+/// Loads the project's [`ProjectConfig`], preferring a dedicated
+/// `tailwindcss.toml` over `[package.metadata.tailwindcss]` in `Cargo.toml`.
+/// Returns the default (all-`None`) config if neither file sets anything.
+fn load_project_config(manifest_path: &Path) -> ProjectConfig {
+    let dedicated_path = manifest_path.join("tailwindcss.toml");
+    if let Ok(contents) = std::fs::read_to_string(&dedicated_path) {
+        return match toml::from_str(&contents) {
+            Ok(config) => config,
+            Err(e) => abort_call_site!(format!("Failed to parse tailwindcss.toml: {}", e)),
+        };
+    }
+
+    #[derive(serde::Deserialize)]
+    struct CargoManifest {
+        #[serde(default)]
+        package: CargoPackage,
+    }
+    #[derive(Default, serde::Deserialize)]
+    struct CargoPackage {
+        #[serde(default)]
+        metadata: CargoPackageMetadata,
+    }
+    #[derive(Default, serde::Deserialize)]
+    struct CargoPackageMetadata {
+        tailwindcss: Option<ProjectConfig>,
+    }
+
+    let cargo_toml_path = manifest_path.join("Cargo.toml");
+    if let Ok(contents) = std::fs::read_to_string(&cargo_toml_path) {
+        let manifest: CargoManifest = match toml::from_str(&contents) {
+            Ok(manifest) => manifest,
+            Err(e) => abort_call_site!(format!("Failed to parse Cargo.toml: {}", e)),
+        };
+        if let Some(config) = manifest.package.metadata.tailwindcss {
+            return config;
+        }
+    }
+
+    ProjectConfig::default()
+}
+
+/// Resolves a single setting from the macro's layered configuration
+/// sources, in precedence order: the explicit macro argument, an
+/// explicitly-named environment variable override, the project config file
+/// (`tailwindcss.toml` / `Cargo.toml` metadata), and finally the built-in
+/// environment variable name.
+fn resolve_setting(
+    arg: Option<String>,
+    explicit_env: Option<&str>,
+    from_config: Option<String>,
+    default_env: &str,
+) -> Option<String> {
+    arg.or_else(|| explicit_env.and_then(|name| std::env::var(name).ok()))
+        .or(from_config)
+        .or_else(|| std::env::var(default_env).ok())
+}
+
+/// Finds the byte index of the `:` in a `content` key that starts its own
+/// line (ignoring leading whitespace), so a nested `content` key used
+/// elsewhere in the config (e.g. under `theme.extend` for CSS `content`
+/// utilities) on the same line as other text isn't mistaken for the
+/// top-level globs array.
+fn find_content_key_colon(config_contents: &str) -> Option<usize> {
+    let mut offset = 0;
+    for line in config_contents.split_inclusive('\n') {
+        let trimmed = line.trim_start();
+        if let Some(rest) = trimmed.strip_prefix("content") {
+            let rest_trimmed = rest.trim_start();
+            if rest_trimmed.starts_with(':') {
+                let consumed = line.len() - rest_trimmed.len();
+                return Some(offset + consumed);
+            }
+        }
+        offset += line.len();
+    }
+    None
+}
+
+/// Best-effort extraction of the string literals inside a Tailwind config's
+/// `content: [...]` array. This is not a JS parser, just a scan for the
+/// common case of a plain array of glob strings; anything more dynamic
+/// (spread expressions, function calls, etc.) is silently skipped.
+fn extract_content_globs(config_contents: &str) -> Vec<String> {
+    let Some(colon_index) = find_content_key_colon(config_contents) else {
+        return Vec::new();
+    };
+    let Some(bracket_start) = config_contents[colon_index..].find('[') else {
+        return Vec::new();
+    };
+    let bracket_start = colon_index + bracket_start;
+    let Some(bracket_end) = config_contents[bracket_start..].find(']') else {
+        return Vec::new();
+    };
+    let list = &config_contents[bracket_start + 1..bracket_start + bracket_end];
+
+    let mut globs = Vec::new();
+    let mut chars = list.chars();
+    while let Some(c) = chars.next() {
+        if c == '\'' || c == '"' || c == '`' {
+            let quote = c;
+            let mut value = String::new();
+            for next in chars.by_ref() {
+                if next == quote {
+                    break;
+                }
+                value.push(next);
+            }
+            globs.push(value);
+        }
+    }
+    globs
+}
+
+/// Expands the `content` globs declared in the Tailwind config (relative to
+/// `manifest_path`) into the list of source files they currently match, so
+/// they can be tracked as macro dependencies alongside `config` and `input`.
+fn resolve_content_files(manifest_path: &Path, config_path: &Path) -> Vec<PathBuf> {
+    let Ok(config_contents) = std::fs::read_to_string(config_path) else {
+        return Vec::new();
+    };
+    extract_content_globs(&config_contents)
+        .into_iter()
+        .flat_map(|pattern| {
+            let pattern_path = manifest_path.join(&pattern);
+            glob::glob(&pattern_path.to_string_lossy())
+                .into_iter()
+                .flatten()
+                .filter_map(Result::ok)
+                .filter(|path| path.is_file())
+        })
+        .collect()
+}
 
 /// Keywords used internally by the application.
 mod kw {
@@ -13,6 +198,38 @@ mod kw {
     syn::custom_keyword!(input_env);
     syn::custom_keyword!(tailwindcss_bin);
     syn::custom_keyword!(tailwindcss_bin_env);
+    syn::custom_keyword!(args);
+    syn::custom_keyword!(minify);
+    syn::custom_keyword!(tailwindcss_cmd);
+}
+
+/// A list of strings given either as a bracketed list of string literals
+/// (`["--postcss", "--content", "foo/**/*.html"]`) or as a single string
+/// literal that gets split on whitespace (`"--postcss --content foo"`),
+/// modeled on how Cargo parses its `StringList` build-config values.
+enum StringList {
+    List(Vec<LitStr>),
+    Single(LitStr),
+}
+impl Parse for StringList {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        if input.peek(syn::token::Bracket) {
+            let content;
+            syn::bracketed!(content in input);
+            let items = Punctuated::<LitStr, Token![,]>::parse_terminated(&content)?;
+            Ok(StringList::List(items.into_iter().collect()))
+        } else {
+            Ok(StringList::Single(input.parse()?))
+        }
+    }
+}
+impl StringList {
+    fn to_values(&self) -> Vec<String> {
+        match self {
+            StringList::List(items) => items.iter().map(LitStr::value).collect(),
+            StringList::Single(lit) => lit.value().split_whitespace().map(String::from).collect(),
+        }
+    }
 }
 
 enum Argument {
@@ -46,6 +263,21 @@ enum Argument {
         _colon_token: Token![:],
         value: LitStr,
     },
+    Args {
+        _kw_token: kw::args,
+        _colon_token: Token![:],
+        value: StringList,
+    },
+    Minify {
+        _kw_token: kw::minify,
+        _colon_token: Token![:],
+        value: LitBool,
+    },
+    TailwindCssCmd {
+        _kw_token: kw::tailwindcss_cmd,
+        _colon_token: Token![:],
+        value: StringList,
+    },
 }
 impl Parse for Argument {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
@@ -86,6 +318,24 @@ impl Parse for Argument {
                 _colon_token: input.parse()?,
                 value: input.parse()?,
             })
+        } else if lookahead1.peek(kw::args) {
+            Ok(Argument::Args {
+                _kw_token: input.parse()?,
+                _colon_token: input.parse()?,
+                value: input.parse()?,
+            })
+        } else if lookahead1.peek(kw::minify) {
+            Ok(Argument::Minify {
+                _kw_token: input.parse()?,
+                _colon_token: input.parse()?,
+                value: input.parse()?,
+            })
+        } else if lookahead1.peek(kw::tailwindcss_cmd) {
+            Ok(Argument::TailwindCssCmd {
+                _kw_token: input.parse()?,
+                _colon_token: input.parse()?,
+                value: input.parse()?,
+            })
         } else {
             Err(lookahead1.error())
         }
@@ -128,6 +378,24 @@ impl Argument {
             _ => None,
         }
     }
+    fn as_args(&self) -> Option<&StringList> {
+        match self {
+            Argument::Args { value, .. } => Some(value),
+            _ => None,
+        }
+    }
+    fn as_minify(&self) -> Option<&LitBool> {
+        match self {
+            Argument::Minify { value, .. } => Some(value),
+            _ => None,
+        }
+    }
+    fn as_tailwindcss_cmd(&self) -> Option<&StringList> {
+        match self {
+            Argument::TailwindCssCmd { value, .. } => Some(value),
+            _ => None,
+        }
+    }
 }
 
 struct MacroArgs {
@@ -176,6 +444,68 @@ impl Parse for MacroArgs {
 ///     tailwindcss_bin: "MY_TAILWINDCSS_BIN_ENV_VAR"
 /// }
 /// ```
+///
+/// `tailwindcss_bin` is the only argument that isn't required: if none of
+/// the lookup sources below provide it, the macro searches `PATH` for a
+/// `tailwindcss` (or `tailwindcss.exe` on Windows) executable and uses the
+/// first match. A bare command name given to `tailwindcss_bin` (e.g. a
+/// renamed or forked binary such as `"twcss"`, rather than a path) is
+/// searched for on `PATH` the same way, by that name; only if no match is
+/// found there is it taken relative to `CARGO_MANIFEST_DIR`.
+///
+/// Rather than repeating `config`, `input`, and `tailwindcss_bin` at every
+/// call site, you can set project-wide defaults in a `tailwindcss.toml`
+/// file, or under `[package.metadata.tailwindcss]` in `Cargo.toml`, both
+/// read from `CARGO_MANIFEST_DIR`:
+///
+/// ```toml
+/// [package.metadata.tailwindcss]
+/// config = "tailwind.config.js"
+/// input = "tailwind.input.css"
+/// tailwindcss_bin = "tailwindcss"
+/// ```
+///
+/// Each setting is resolved in this order: the macro argument, the
+/// explicitly-named env var (via `config_env`/`input_env`/
+/// `tailwindcss_bin_env`), the config file, then the built-in env var name.
+///
+/// The command line passed to tailwindcss is `-c <config> -i <input>`, plus
+/// `--minify` unless the `minify: false` argument (or `minify` config file
+/// key) is given, plus any flags from `args`. `args` is appended verbatim
+/// and accepts either a bracketed list of string literals or a single
+/// whitespace-split string literal:
+///
+/// ```rust
+/// include_tailwind! {
+///     config: "path/to/tailwind.config.js",
+///     input: "path/to/tailwind.input.js",
+///     minify: false,
+///     args: ["--postcss", "-o", "-"],
+/// }
+/// ```
+///
+/// The macro tracks `config`, `input`, and (on a best-effort basis) the
+/// source files matched by the config's `content` globs as dependencies, so
+/// editing any of them triggers a recompile instead of reusing a stale
+/// cached expansion.
+///
+/// If your project doesn't have a standalone `tailwindcss` binary and runs
+/// it through a runner instead (`npx tailwindcss`, `bunx tailwindcss`, `node
+/// ./node_modules/.bin/tailwindcss`, ...), use `tailwindcss_cmd` in place of
+/// `tailwindcss_bin` — the two are mutually exclusive. The first element is
+/// the program to run and any remaining elements are leading arguments
+/// spliced in before `-c`/`-i`/`--minify`:
+///
+/// ```rust
+/// include_tailwind! {
+///     config: "path/to/tailwind.config.js",
+///     input: "path/to/tailwind.input.js",
+///     tailwindcss_cmd: ["npx", "tailwindcss"],
+/// }
+/// ```
+///
+/// Unlike `tailwindcss_bin`, the program named by `tailwindcss_cmd` is not
+/// required to exist on disk as a path, since the runner resolves it.
 #[proc_macro_error]
 #[proc_macro]
 pub fn include_tailwind(item: TokenStream) -> TokenStream {
@@ -208,46 +538,71 @@ pub fn include_tailwind(item: TokenStream) -> TokenStream {
         .copied()
         .filter_map(Argument::as_tailwindcss_bin_env)
         .next();
+    let extra_args = args.iter().copied().filter_map(Argument::as_args).next();
+    let minify = args.iter().copied().filter_map(Argument::as_minify).next();
+    let tailwindcss_cmd = args
+        .iter()
+        .copied()
+        .filter_map(Argument::as_tailwindcss_cmd)
+        .next();
+
+    if tailwindcss_cmd.is_some() && tailwindcss_bin.is_some() {
+        abort_call_site!("`tailwindcss_bin` and `tailwindcss_cmd` are mutually exclusive");
+    }
+    let tailwindcss_cmd = tailwindcss_cmd.map(StringList::to_values);
+    if matches!(&tailwindcss_cmd, Some(parts) if parts.is_empty()) {
+        abort_call_site!("`tailwindcss_cmd` must name at least a program to run");
+    }
 
     let config_env = config_env.map(LitStr::value);
-    let config_env = config_env.as_deref().unwrap_or("TAILWINDCSS_CONFIG");
     let input_env = input_env.map(LitStr::value);
-    let input_env = input_env.as_deref().unwrap_or("TAILWINDCSS_INPUT");
     let tailwindcss_bin_env = tailwindcss_bin_env.map(LitStr::value);
-    let tailwindcss_bin_env = tailwindcss_bin_env.as_deref().unwrap_or("TAILWINDCSS_BIN");
 
+    let project_config = load_project_config(&manifest_path);
+
+    let config = resolve_setting(
+        config.map(LitStr::value),
+        config_env.as_deref(),
+        project_config.config.clone(),
+        "TAILWINDCSS_CONFIG",
+    );
     let config = match config {
-        Some(config) => config.value(),
-        None => match std::env::var(config_env) {
-            Ok(config) => config,
-            Err(e) => abort_call_site!(format!(
-                "Required `config` arg or `TAILWINDCSS_CONFIG` env var: {}",
-                e
-            )),
-        },
+        Some(config) => config,
+        None => abort_call_site!(
+            "Required `config` arg, `TAILWINDCSS_CONFIG` env var, or `config` key in tailwindcss.toml"
+        ),
     };
 
+    let input = resolve_setting(
+        input.map(LitStr::value),
+        input_env.as_deref(),
+        project_config.input.clone(),
+        "TAILWINDCSS_INPUT",
+    );
     let input = match input {
-        Some(input) => input.value(),
-        None => match std::env::var(input_env) {
-            Ok(input) => input,
-            Err(e) => abort_call_site!(format!(
-                "Required `input` arg or `TAILWINDCSS_INPUT` env var: {}",
-                e
-            )),
-        },
+        Some(input) => input,
+        None => abort_call_site!(
+            "Required `input` arg, `TAILWINDCSS_INPUT` env var, or `input` key in tailwindcss.toml"
+        ),
     };
 
-    let tailwindcss_bin = match tailwindcss_bin {
-        Some(tailwindcss_bin) => tailwindcss_bin.value(),
-        None => match std::env::var(tailwindcss_bin_env) {
-            Ok(bin) => bin,
-            Err(e) => abort_call_site!(format!(
-                "Required `tailwindcss_bin` arg or `TAILWINDCSS_BIN` env var: {}",
-                e
-            )),
-        },
-    };
+    // Unlike `config` and `input`, `tailwindcss_bin` is optional: if none of
+    // the lookup sources provide it, we fall back to searching `PATH` below.
+    let tailwindcss_bin = resolve_setting(
+        tailwindcss_bin.map(LitStr::value),
+        tailwindcss_bin_env.as_deref(),
+        project_config.tailwindcss_bin.clone(),
+        "TAILWINDCSS_BIN",
+    );
+
+    let extra_args = extra_args
+        .map(StringList::to_values)
+        .or_else(|| project_config.args.clone())
+        .unwrap_or_default();
+    let minify = minify
+        .map(LitBool::value)
+        .or(project_config.minify)
+        .unwrap_or(true);
 
     let mut config_path = match config.parse::<PathBuf>() {
         Ok(path) => path,
@@ -283,33 +638,79 @@ pub fn include_tailwind(item: TokenStream) -> TokenStream {
         )
     }
 
-    let mut tailwindcss_bin_path = match tailwindcss_bin.parse::<PathBuf>() {
-        Ok(path) => path,
-        Err(e) => abort!(
-            tailwindcss_bin,
-            format!("Provided tailwindcss_bin is not a path: {}", e)
-        ),
-    };
-    if tailwindcss_bin_path.is_relative() {
-        tailwindcss_bin_path = manifest_path.join(tailwindcss_bin_path);
-    }
-    if !tailwindcss_bin_path.exists() {
-        abort!(
-            tailwindcss_bin,
-            format!(
-                "The tailwindcss_bin path does not exist: {}",
-                tailwindcss_bin_path.to_string_lossy()
-            )
-        )
-    }
+    // Track the config, the input, and whatever source files the config's
+    // `content` globs currently match, so that editing any of them (e.g.
+    // adding a class to a template) invalidates this macro expansion and
+    // forces a rebuild.
+    let mut tracked_paths = vec![config_path.clone(), input_path.clone()];
+    tracked_paths.extend(resolve_content_files(&manifest_path, &config_path));
 
-    let tw_proc_output = std::process::Command::new(tailwindcss_bin_path)
+    // `tailwindcss_cmd` runs tailwindcss through a runner (npx/bunx/node ...)
+    // instead of a standalone binary, so the program name may not resolve to
+    // a file on disk at all: the runner resolves it. In that case we skip
+    // the `tailwindcss_bin` resolution (and its existence check) entirely.
+    let mut tw_command = match tailwindcss_cmd {
+        Some(mut cmd_parts) => {
+            let program = cmd_parts.remove(0);
+            let mut command = std::process::Command::new(program);
+            command.args(cmd_parts);
+            command
+        }
+        None => {
+            let tailwindcss_bin_path = match tailwindcss_bin {
+                // A bare command name (no directory component), e.g.
+                // `"twcss"`, is resolved against `PATH` by that name first,
+                // and only treated as a path relative to `CARGO_MANIFEST_DIR`
+                // if `PATH` has no match.
+                Some(tailwindcss_bin) if !tailwindcss_bin.chars().any(std::path::is_separator) => {
+                    find_executable_on_path(&tailwindcss_bin)
+                        .unwrap_or_else(|| manifest_path.join(&tailwindcss_bin))
+                }
+                Some(tailwindcss_bin) => {
+                    let path = match tailwindcss_bin.parse::<PathBuf>() {
+                        Ok(path) => path,
+                        Err(e) => abort!(
+                            tailwindcss_bin,
+                            format!("Provided tailwindcss_bin is not a path: {}", e)
+                        ),
+                    };
+                    if path.is_relative() {
+                        manifest_path.join(path)
+                    } else {
+                        path
+                    }
+                }
+                // No explicit `tailwindcss_bin` was given at all: search
+                // `PATH` for the standalone CLI.
+                None => match find_executable_on_path("tailwindcss") {
+                    Some(path) => path,
+                    None => abort_call_site!(
+                        "Could not find a `tailwindcss` binary on PATH; set the `tailwindcss_bin` \
+                         argument, the `TAILWINDCSS_BIN` env var, use `tailwindcss_cmd` to run it \
+                         through a runner, or install the standalone tailwindcss CLI"
+                    ),
+                },
+            };
+            if !tailwindcss_bin_path.exists() {
+                abort_call_site!(format!(
+                    "The tailwindcss_bin path does not exist: {}",
+                    tailwindcss_bin_path.to_string_lossy()
+                ))
+            }
+            std::process::Command::new(tailwindcss_bin_path)
+        }
+    };
+    tw_command
         .arg("-c")
         .arg(config_path)
         .arg("-i")
-        .arg(input_path)
-        .arg("--minify")
-        .output();
+        .arg(input_path);
+    if minify {
+        tw_command.arg("--minify");
+    }
+    tw_command.args(&extra_args);
+
+    let tw_proc_output = tw_command.output();
 
     let tw_proc_output = match tw_proc_output {
         Ok(tw_proc) => tw_proc,
@@ -327,8 +728,179 @@ pub fn include_tailwind(item: TokenStream) -> TokenStream {
 
     let tw_content_lit = LitStr::new(tw_content_str, Span::call_site());
 
+    let tracked_includes = tracked_paths.iter().map(|path| {
+        let path_lit = LitStr::new(&path.to_string_lossy(), Span::call_site());
+        quote! { const _: &[u8] = include_bytes!(#path_lit); }
+    });
+
     quote! {
-        #tw_content_lit
+        {
+            #(#tracked_includes)*
+            #tw_content_lit
+        }
     }
     .into()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(unix)]
+    fn make_executable(path: &Path) {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::write(path, b"#!/bin/sh\n").unwrap();
+        std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o755)).unwrap();
+    }
+
+    #[cfg(not(unix))]
+    fn make_executable(path: &Path) {
+        std::fs::write(path, b"").unwrap();
+    }
+
+    #[test]
+    fn find_executable_on_path_searches_for_the_requested_name() {
+        let dir = std::env::temp_dir().join(format!(
+            "tailwindcss-native-rust-macro-test-{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+        make_executable(&dir.join(executable_file_name("my-tool")));
+        std::fs::write(dir.join(executable_file_name("not-my-tool")), b"").unwrap();
+
+        let previous_path = std::env::var_os("PATH");
+        std::env::set_var("PATH", &dir);
+
+        let found = find_executable_on_path("my-tool");
+        let not_found = find_executable_on_path("some-other-name");
+
+        match previous_path {
+            Some(path) => std::env::set_var("PATH", path),
+            None => std::env::remove_var("PATH"),
+        }
+        std::fs::remove_dir_all(&dir).ok();
+
+        assert_eq!(found, Some(dir.join(executable_file_name("my-tool"))));
+        assert_eq!(not_found, None);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn is_executable_file_requires_the_executable_bit() {
+        let dir = std::env::temp_dir().join(format!(
+            "tailwindcss-native-rust-macro-test-bit-{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+        let not_executable = dir.join("not-executable");
+        std::fs::write(&not_executable, b"").unwrap();
+        let executable = dir.join("executable");
+        make_executable(&executable);
+
+        let not_executable_result = is_executable_file(&not_executable);
+        let executable_result = is_executable_file(&executable);
+        std::fs::remove_dir_all(&dir).ok();
+
+        assert!(!not_executable_result);
+        assert!(executable_result);
+    }
+
+    #[test]
+    fn resolve_setting_prefers_explicit_argument() {
+        let resolved = resolve_setting(
+            Some("from-arg".to_string()),
+            Some("RESOLVE_SETTING_TEST_ARG_ENV"),
+            Some("from-config".to_string()),
+            "RESOLVE_SETTING_TEST_ARG_DEFAULT_ENV",
+        );
+        assert_eq!(resolved.as_deref(), Some("from-arg"));
+    }
+
+    #[test]
+    fn resolve_setting_prefers_explicit_env_over_config() {
+        std::env::set_var("RESOLVE_SETTING_TEST_EXPLICIT_ENV", "from-env");
+        let resolved = resolve_setting(
+            None,
+            Some("RESOLVE_SETTING_TEST_EXPLICIT_ENV"),
+            Some("from-config".to_string()),
+            "RESOLVE_SETTING_TEST_EXPLICIT_ENV_DEFAULT",
+        );
+        std::env::remove_var("RESOLVE_SETTING_TEST_EXPLICIT_ENV");
+        assert_eq!(resolved.as_deref(), Some("from-env"));
+    }
+
+    #[test]
+    fn resolve_setting_prefers_config_over_default_env() {
+        std::env::set_var("RESOLVE_SETTING_TEST_DEFAULT_ENV", "from-default-env");
+        let resolved = resolve_setting(
+            None,
+            None,
+            Some("from-config".to_string()),
+            "RESOLVE_SETTING_TEST_DEFAULT_ENV",
+        );
+        std::env::remove_var("RESOLVE_SETTING_TEST_DEFAULT_ENV");
+        assert_eq!(resolved.as_deref(), Some("from-config"));
+    }
+
+    #[test]
+    fn resolve_setting_falls_back_to_default_env() {
+        std::env::set_var("RESOLVE_SETTING_TEST_FALLBACK_ENV", "from-default-env");
+        let resolved = resolve_setting(None, None, None, "RESOLVE_SETTING_TEST_FALLBACK_ENV");
+        std::env::remove_var("RESOLVE_SETTING_TEST_FALLBACK_ENV");
+        assert_eq!(resolved.as_deref(), Some("from-default-env"));
+    }
+
+    #[test]
+    fn resolve_setting_returns_none_when_nothing_is_set() {
+        let resolved = resolve_setting(None, None, None, "RESOLVE_SETTING_TEST_UNSET_ENV");
+        assert_eq!(resolved, None);
+    }
+
+    #[test]
+    fn string_list_parses_bracketed_list() {
+        let list: StringList = syn::parse_str(r#"["--postcss", "-o", "-"]"#).unwrap();
+        assert_eq!(list.to_values(), vec!["--postcss", "-o", "-"]);
+    }
+
+    #[test]
+    fn string_list_parses_single_whitespace_split_literal() {
+        let list: StringList = syn::parse_str(r#""--postcss -o -""#).unwrap();
+        assert_eq!(list.to_values(), vec!["--postcss", "-o", "-"]);
+    }
+
+    #[test]
+    fn string_list_single_literal_with_no_whitespace_is_one_value() {
+        let list: StringList = syn::parse_str(r#""tailwindcss""#).unwrap();
+        assert_eq!(list.to_values(), vec!["tailwindcss"]);
+    }
+
+    #[test]
+    fn extract_content_globs_reads_the_top_level_array() {
+        let config = r#"
+module.exports = {
+  content: ['./src/**/*.html', './templates/**/*.rs'],
+};
+"#;
+        assert_eq!(
+            extract_content_globs(config),
+            vec!["./src/**/*.html", "./templates/**/*.rs"],
+        );
+    }
+
+    #[test]
+    fn extract_content_globs_ignores_a_nested_decoy_on_the_same_line() {
+        let config = r#"
+module.exports = {
+  theme: { extend: { content: ['"decoy"'] } },
+  content: ['./src/**/*.html'],
+};
+"#;
+        assert_eq!(extract_content_globs(config), vec!["./src/**/*.html"]);
+    }
+
+    #[test]
+    fn extract_content_globs_returns_empty_without_a_content_key() {
+        let config = "module.exports = { theme: {} };";
+        assert!(extract_content_globs(config).is_empty());
+    }
+}